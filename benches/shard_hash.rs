@@ -0,0 +1,22 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use htcache::ShardHash;
+
+/// Time the shard hashes take for keys of a typical length
+fn shard_hashes(c: &mut Criterion) {
+    let keys: Vec<String> = (0..1000).map(|i| format!("user:{}", i)).collect();
+    let mut group = c.benchmark_group("shard_hash");
+
+    for hash in [ShardHash::Xxh3, ShardHash::Fnv, ShardHash::Sip] {
+        let id = BenchmarkId::from_parameter(format!("{:?}", hash));
+        group.bench_with_input(id, &keys, |bench, keys| {
+            bench.iter(|| {
+                keys.iter()
+                    .fold(0u64, |sum, key| sum.wrapping_add(hash.hash(black_box(key))))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, shard_hashes);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Parts of the service shared with its benchmarks
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+use std::str::FromStr;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Hash of the key which picks its shard
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShardHash {
+    Xxh3,
+    /// FNV-1a, fast for short keys
+    Fnv,
+    /// SipHash of the standard library, which may change between builds
+    Sip,
+}
+
+impl ShardHash {
+    pub fn hash(self, key: &str) -> u64 {
+        match self {
+            Self::Xxh3 => xxh3_64(key.as_bytes()),
+            Self::Fnv => key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            }),
+            Self::Sip => {
+                let mut hasher = DefaultHasher::new();
+                hasher.write(key.as_bytes());
+                hasher.finish()
+            }
+        }
+    }
+}
+
+impl FromStr for ShardHash {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "xxh3" => Ok(Self::Xxh3),
+            "fnv" => Ok(Self::Fnv),
+            "sip" => Ok(Self::Sip),
+            _ => Err(format!("unknown shard hash {}", name)),
+        }
+    }
+}